@@ -0,0 +1,56 @@
+//! Async equivalent of [`Si7021`](crate::Si7021), built on [`embedded-hal-async`]'s `I2c`.
+//!
+//! [`embedded-hal-async`]: https://crates.io/crates/embedded-hal-async
+
+use byteorder::{BigEndian, ByteOrder};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{Error as _, ErrorKind, I2c};
+
+use crate::{
+    calculate_relative_humidity, calculate_temperature, crc8, heater_current_ma, si7021_driver_impl,
+    DeviceId, Error, Resolution, FIRMWARE_REVISION_ACCESS, HEATER_CURRENT_MASK, HEATER_ENABLE_BIT,
+    MEASURE_RELATIVE_HUMIDITY, MEASURE_RELATIVE_HUMIDITY_NO_HOLD, MEASURE_TEMPERATURE,
+    MEASURE_TEMPERATURE_NO_HOLD, READ_HEATER_CONTROL_REGISTER, READ_TEMPERATURE,
+    READ_USER_REGISTER_1, RESET, RESET_DELAY_MS, RESOLUTION_MASK, SERIAL_NUMBER_FIRST_ACCESS,
+    SERIAL_NUMBER_SECOND_ACCESS, SI7021_I2C_ADDRESS, WRITE_HEATER_CONTROL_REGISTER,
+    WRITE_USER_REGISTER_1,
+};
+
+/// Read temperature and relative humidity from a Si7021 over a non-blocking `I2c` bus.
+///
+/// Mirrors [`Si7021`](crate::Si7021), but every method is `async`. The Hold Master measurements
+/// ([`relative_humidity`](Self::relative_humidity), [`temperature_celsius`](Self::temperature_celsius)
+/// and their `_checked` variants) still stretch the I²C clock for the whole conversion; the
+/// `start_*`/`try_read_*` methods do not.
+#[derive(Clone, Debug)]
+pub struct Si7021Async<T> {
+    i2c: T,
+    address: u8,
+}
+
+si7021_driver_impl! {
+    Si7021Async,
+    i2c_trait = I2c,
+    delay_trait = DelayNs,
+    hal_crate = "embedded-hal-async",
+    wait_verb = "awaits",
+    rh_link = Self::relative_humidity,
+    temp_link = Self::temperature_celsius,
+    async_kw = [async],
+    await_kw = [.await],
+    extra_methods = {
+        /// Read the most recently measured relative humidity.
+        pub async fn relative_humidity(&mut self) -> Result<f32, T::Error> {
+            let raw_humidity = self.read_word(MEASURE_RELATIVE_HUMIDITY).await?;
+
+            Ok(calculate_relative_humidity(raw_humidity))
+        }
+
+        /// Read the most recently measured temperature.
+        pub async fn temperature_celsius(&mut self) -> Result<f32, T::Error> {
+            let raw_temperature = self.read_word(MEASURE_TEMPERATURE).await?;
+
+            Ok(calculate_temperature(raw_temperature))
+        }
+    }
+}
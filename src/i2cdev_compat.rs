@@ -0,0 +1,53 @@
+//! Thin [`embedded-hal`] [`I2c`] adapter over the Linux-only [`i2cdev`] crate, for existing
+//! users of this crate's original `i2cdev`-based API.
+//!
+//! [`embedded-hal`]: https://crates.io/crates/embedded-hal
+//! [`i2cdev`]: https://crates.io/crates/i2cdev
+
+use core::fmt;
+
+use embedded_hal::i2c::{Error, ErrorKind, ErrorType, I2c, Operation};
+use i2cdev::core::I2CDevice;
+
+/// Wraps an [`i2cdev::core::I2CDevice`] (already bound to its slave address) so it can be
+/// used anywhere an `embedded-hal` [`I2c`] is expected.
+#[derive(Clone, Debug)]
+pub struct I2cdevCompat<T>(pub T);
+
+/// Wraps an [`i2cdev::core::I2CDevice`]'s error so it implements `embedded-hal`'s [`Error`].
+#[derive(Debug)]
+pub struct I2cdevError<E>(pub E);
+
+impl<E: fmt::Debug> Error for I2cdevError<E> {
+    fn kind(&self) -> ErrorKind {
+        // i2cdev doesn't expose a finer-grained error kind.
+        ErrorKind::Other
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for I2cdevError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "i2cdev error: {:?}", self.0)
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for I2cdevError<E> {}
+
+impl<T: I2CDevice> ErrorType for I2cdevCompat<T> {
+    type Error = I2cdevError<T::Error>;
+}
+
+impl<T: I2CDevice> I2c for I2cdevCompat<T> {
+    // The wrapped `I2CDevice` is already bound to a slave address when opened, so the
+    // per-transaction address is ignored here.
+    fn transaction(&mut self, _address: u8, operations: &mut [Operation]) -> Result<(), Self::Error> {
+        for operation in operations {
+            match operation {
+                Operation::Read(buf) => self.0.read(buf).map_err(I2cdevError)?,
+                Operation::Write(buf) => self.0.write(buf).map_err(I2cdevError)?,
+            }
+        }
+
+        Ok(())
+    }
+}
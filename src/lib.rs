@@ -1,59 +1,479 @@
 //! I²C driver for the Silicon Labs [Si7021] hygrometer and thermometer.
 //!
+//! The driver is generic over [`embedded-hal`]'s [`I2c`] trait, so it runs on any platform with
+//! an `embedded-hal` implementation, not just Linux. Enable the `i2cdev` feature (on by default)
+//! for a thin adapter over the Linux-only [`i2cdev`] crate, or the `async` feature for a
+//! non-blocking API built on [`embedded-hal-async`].
+//!
 //! [Si7021]: https://www.silabs.com/documents/public/data-sheets/Si7021-A20.pdf
+//! [`embedded-hal`]: https://crates.io/crates/embedded-hal
+//! [`I2c`]: embedded_hal::i2c::I2c
+//! [`i2cdev`]: https://crates.io/crates/i2cdev
+//! [`embedded-hal-async`]: https://crates.io/crates/embedded-hal-async
+//!
+//! The `std` feature (on by default, pulled in by `i2cdev` and `i2csensors` below) is the only
+//! thing tying this crate to `std`; disable it along with them for bare-metal `no_std` targets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate byteorder;
+extern crate embedded_hal;
+
+#[cfg(feature = "async")]
+extern crate embedded_hal_async;
+#[cfg(feature = "i2cdev")]
 extern crate i2cdev;
+#[cfg(feature = "i2csensors")]
 extern crate i2csensors;
 
+#[cfg(feature = "async")]
+pub mod asynch;
+#[cfg(feature = "i2cdev")]
+pub mod i2cdev_compat;
+
+use core::fmt;
+
 use byteorder::{BigEndian, ByteOrder};
-use i2cdev::core::I2CDevice;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{Error as _, ErrorKind, I2c};
+#[cfg(feature = "i2csensors")]
 use i2csensors::{Hygrometer, Thermometer};
 
 /// Standard I²C address of the Si7021: `0x40`
-pub const SI7021_I2C_ADDRESS: u16 = 0x40;
+pub const SI7021_I2C_ADDRESS: u8 = 0x40;
 
 // Some of the supported commands
-// currently missing: accuracy control, heater, reset, async interface
 const MEASURE_RELATIVE_HUMIDITY: u8 = 0xE5;
 const MEASURE_TEMPERATURE: u8 = 0xE3;
+const MEASURE_RELATIVE_HUMIDITY_NO_HOLD: u8 = 0xF5;
+const MEASURE_TEMPERATURE_NO_HOLD: u8 = 0xF3;
 const READ_TEMPERATURE: u8 = 0xE0;
+const RESET: u8 = 0xFE;
+const READ_USER_REGISTER_1: u8 = 0xE7;
+const WRITE_USER_REGISTER_1: u8 = 0xE6;
+const READ_HEATER_CONTROL_REGISTER: u8 = 0x11;
+const WRITE_HEATER_CONTROL_REGISTER: u8 = 0x51;
+const SERIAL_NUMBER_FIRST_ACCESS: [u8; 2] = [0xFA, 0x0F];
+const SERIAL_NUMBER_SECOND_ACCESS: [u8; 2] = [0xFC, 0xC9];
+const FIRMWARE_REVISION_ACCESS: [u8; 2] = [0x84, 0xB8];
 
-/// Read temperature and relative humidity from a Si7021
-#[derive(Clone, Debug)]
-pub struct Si7021<T> {
-    device: T,
+// Bits D7 and D0 of User Register 1 select the measurement resolution, the
+// remaining bits are reserved and must be preserved across a read/modify/write.
+const RESOLUTION_MASK: u8 = 0b1000_0001;
+
+// Bit 2 (HTRE) of User Register 1 enables the on-chip heater.
+const HEATER_ENABLE_BIT: u8 = 0b0000_0100;
+
+// The low 4 bits of the Heater Control Register select one of 16 heater
+// current steps, the remaining bits are reserved.
+const HEATER_CURRENT_MASK: u8 = 0b0000_1111;
+
+// Approximate heater current, in mA, at the lowest and highest of the 16 current steps,
+// per the datasheet. The steps in between increase roughly linearly.
+const HEATER_CURRENT_MIN_MA: f32 = 3.09;
+const HEATER_CURRENT_MAX_MA: f32 = 94.20;
+
+/// Time the sensor needs to power back up after a [`reset`](Si7021::reset), per the datasheet.
+const RESET_DELAY_MS: u64 = 15;
+
+// Polynomial for the CRC-8 checksum the sensor can append to a measurement:
+// x^8 + x^5 + x^4 + 1, MSB first, with an initial value of 0x00.
+const CRC8_POLYNOMIAL: u8 = 0x31;
+
+/// An error communicating with the sensor over I²C, or a CRC mismatch on a checked measurement.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error<E> {
+    /// The underlying I²C transaction failed.
+    I2C(E),
+    /// The CRC-8 checksum appended to the measurement did not match the data.
+    Crc,
 }
 
-impl<T> Si7021<T>
-    where T: I2CDevice
-{
-    /// Create a new instance wrapping the given `I2CDevice`.
-    pub fn new(device: T) -> Si7021<T> {
-        Si7021 {
-            device,
+impl<E> From<E> for Error<E> {
+    fn from(err: E) -> Error<E> {
+        Error::I2C(err)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::I2C(err) => write!(f, "I2C error: {}", err),
+            Error::Crc => write!(f, "CRC checksum mismatch"),
         }
     }
+}
 
-    /// Every humidity measurement measures the temperature first. Use this
-    /// function to read the most recently measured temperature.
-    pub fn last_temperature(&mut self) -> Result<f32, T::Error> {
-        let raw_temperature = self.read_word(READ_TEMPERATURE)?;
+impl<E: fmt::Debug + fmt::Display> core::error::Error for Error<E> {}
 
-        Ok(calculate_temperature(raw_temperature))
+fn crc8(bytes: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in bytes {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ CRC8_POLYNOMIAL
+            } else {
+                crc << 1
+            };
+        }
     }
+    crc
+}
 
-    fn read_word(&mut self, command: u8) -> Result<u16, T::Error> {
-        let mut buf = [0u8; 2];
-        self.device.write(&[command])?;
-        self.device.read(&mut buf)?;
+/// Measurement resolution, trading accuracy for conversion time.
+///
+/// Selected via bits D7 and D0 of User Register 1.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Resolution {
+    /// 12 bit relative humidity, 14 bit temperature (power-on default).
+    Rh12Temp14,
+    /// 8 bit relative humidity, 12 bit temperature.
+    Rh8Temp12,
+    /// 10 bit relative humidity, 13 bit temperature.
+    Rh10Temp13,
+    /// 11 bit relative humidity, 11 bit temperature.
+    Rh11Temp11,
+}
 
-        Ok(BigEndian::read_u16(&buf))
+impl Resolution {
+    fn from_bits(bits: u8) -> Resolution {
+        match bits & RESOLUTION_MASK {
+            0b0000_0000 => Resolution::Rh12Temp14,
+            0b0000_0001 => Resolution::Rh8Temp12,
+            0b1000_0000 => Resolution::Rh10Temp13,
+            0b1000_0001 => Resolution::Rh11Temp11,
+            _ => unreachable!(),
+        }
+    }
+
+    fn to_bits(self) -> u8 {
+        match self {
+            Resolution::Rh12Temp14 => 0b0000_0000,
+            Resolution::Rh8Temp12 => 0b0000_0001,
+            Resolution::Rh10Temp13 => 0b1000_0000,
+            Resolution::Rh11Temp11 => 0b1000_0001,
+        }
+    }
+}
+
+/// Identifies which member of the Si7013/Si7020/Si7021 family is on the bus, decoded from the
+/// device-ID byte returned as part of the electronic serial number.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DeviceId {
+    /// Si7013.
+    Si7013,
+    /// Si7020.
+    Si7020,
+    /// Si7021.
+    Si7021,
+    /// An engineering sample, not a production part.
+    EngineeringSample,
+    /// A device-ID byte not recognized by this crate.
+    Unknown(u8),
+}
+
+impl DeviceId {
+    fn from_byte(byte: u8) -> DeviceId {
+        match byte {
+            0x00 | 0xFF => DeviceId::EngineeringSample,
+            0x0D => DeviceId::Si7013,
+            0x14 => DeviceId::Si7020,
+            0x15 => DeviceId::Si7021,
+            other => DeviceId::Unknown(other),
+        }
     }
 }
 
+// The sync (`Si7021`) and async (`Si7021Async`) drivers talk to the sensor exactly the same
+// way; the only difference is the `embedded-hal` vs `embedded-hal-async` `I2c`/`DelayNs` traits
+// and the resulting `async`/`.await` keywords. Generate both impl blocks from one copy of the
+// logic instead of maintaining two drifting ~250-line copies.
+//
+// `async_kw`/`await_kw` are passed as bracketed token lists (empty for the sync instantiation)
+// rather than a single on/off flag, since `macro_rules!` has no conditional compilation of its
+// own; splicing in an empty token list is how the sync side ends up with plain `fn`/no `.await`.
+macro_rules! si7021_driver_impl {
+    (
+        $Driver:ident,
+        i2c_trait = $I2cTrait:path,
+        delay_trait = $DelayTrait:path,
+        hal_crate = $hal_crate:literal,
+        wait_verb = $wait_verb:literal,
+        rh_link = $rh_link:path,
+        temp_link = $temp_link:path,
+        async_kw = [$($async_kw:tt)*],
+        await_kw = [$($await_kw:tt)*],
+        extra_methods = { $($extra:item)* }
+    ) => {
+        impl<T> $Driver<T>
+            where T: $I2cTrait
+        {
+            #[doc = concat!("Create a new instance wrapping the given `", $hal_crate, "` `I2c` bus, talking to the")]
+            /// sensor at the standard address ([`SI7021_I2C_ADDRESS`]).
+            pub fn new(i2c: T) -> $Driver<T> {
+                $Driver::with_address(i2c, SI7021_I2C_ADDRESS)
+            }
+
+            #[doc = concat!("Create a new instance wrapping the given `", $hal_crate, "` `I2c` bus, talking to the")]
+            /// sensor at a non-standard address.
+            pub fn with_address(i2c: T, address: u8) -> $Driver<T> {
+                $Driver { i2c, address }
+            }
+
+            /// Every humidity measurement measures the temperature first. Use this
+            /// function to read the most recently measured temperature.
+            pub $($async_kw)* fn last_temperature(&mut self) -> Result<f32, T::Error> {
+                let raw_temperature = self.read_word(READ_TEMPERATURE)$($await_kw)*?;
+
+                Ok(calculate_temperature(raw_temperature))
+            }
+
+            /// Perform a software reset of the sensor, restoring its default settings.
+            ///
+            #[doc = concat!("This ", $wait_verb, " the ~15 ms the sensor needs to power back up, per the datasheet,")]
+            #[doc = concat!("using the given `", $hal_crate, "` delay provider.")]
+            pub $($async_kw)* fn reset<D: $DelayTrait>(&mut self, delay: &mut D) -> Result<(), T::Error> {
+                self.i2c.write(self.address, &[RESET])$($await_kw)*?;
+                delay.delay_ms(RESET_DELAY_MS as u32)$($await_kw)*;
+
+                Ok(())
+            }
+
+            /// Read the currently configured measurement [`Resolution`].
+            pub $($async_kw)* fn resolution(&mut self) -> Result<Resolution, T::Error> {
+                let register = self.read_register(READ_USER_REGISTER_1)$($await_kw)*?;
+
+                Ok(Resolution::from_bits(register))
+            }
+
+            /// Configure the measurement [`Resolution`], trading accuracy for conversion time.
+            pub $($async_kw)* fn set_resolution(&mut self, resolution: Resolution) -> Result<(), T::Error> {
+                let register = self.read_register(READ_USER_REGISTER_1)$($await_kw)*?;
+                let register = (register & !RESOLUTION_MASK) | resolution.to_bits();
+
+                self.write_register(WRITE_USER_REGISTER_1, register)$($await_kw)*
+            }
+
+            #[doc = concat!("Like [`relative_humidity`](", stringify!($rh_link), "), but validates the CRC-8")]
+            /// checksum the sensor appends to the measurement, for use on noisy buses.
+            pub $($async_kw)* fn relative_humidity_checked(&mut self) -> Result<f32, Error<T::Error>> {
+                let raw_humidity = self.read_word_checked(MEASURE_RELATIVE_HUMIDITY)$($await_kw)*?;
+
+                Ok(calculate_relative_humidity(raw_humidity))
+            }
+
+            #[doc = concat!("Like [`temperature_celsius`](", stringify!($temp_link), "), but validates the CRC-8")]
+            /// checksum the sensor appends to the measurement, for use on noisy buses.
+            pub $($async_kw)* fn temperature_celsius_checked(&mut self) -> Result<f32, Error<T::Error>> {
+                let raw_temperature = self.read_word_checked(MEASURE_TEMPERATURE)$($await_kw)*?;
+
+                Ok(calculate_temperature(raw_temperature))
+            }
+
+            /// Start a relative humidity measurement without holding the I²C clock, returning
+            /// immediately. Poll [`try_read_humidity`](Self::try_read_humidity) for the result.
+            ///
+            #[doc = concat!("Use this instead of [`relative_humidity`](", stringify!($rh_link), ") on masters")]
+            /// that don't tolerate the several-millisecond clock stretch of the Hold Master command.
+            pub $($async_kw)* fn start_relative_humidity(&mut self) -> Result<(), T::Error> {
+                self.i2c.write(self.address, &[MEASURE_RELATIVE_HUMIDITY_NO_HOLD])$($await_kw)*
+            }
+
+            /// Start a temperature measurement without holding the I²C clock, returning immediately.
+            /// Poll [`try_read_temperature`](Self::try_read_temperature) for the result.
+            ///
+            #[doc = concat!("Use this instead of [`temperature_celsius`](", stringify!($temp_link), ") on masters")]
+            /// that don't tolerate the several-millisecond clock stretch of the Hold Master command.
+            pub $($async_kw)* fn start_temperature(&mut self) -> Result<(), T::Error> {
+                self.i2c.write(self.address, &[MEASURE_TEMPERATURE_NO_HOLD])$($await_kw)*
+            }
+
+            /// Poll for the result of a measurement started with
+            /// [`start_relative_humidity`](Self::start_relative_humidity).
+            ///
+            /// Returns `Ok(None)` while the sensor is still converting and NACKs the read, and
+            /// `Ok(Some(value))` once the measurement is ready.
+            pub $($async_kw)* fn try_read_humidity(&mut self) -> Result<Option<f32>, T::Error> {
+                Ok(self.try_read_word()$($await_kw)*?.map(calculate_relative_humidity))
+            }
+
+            /// Poll for the result of a measurement started with
+            /// [`start_temperature`](Self::start_temperature).
+            ///
+            /// Returns `Ok(None)` while the sensor is still converting and NACKs the read, and
+            /// `Ok(Some(value))` once the measurement is ready.
+            pub $($async_kw)* fn try_read_temperature(&mut self) -> Result<Option<f32>, T::Error> {
+                Ok(self.try_read_word()$($await_kw)*?.map(calculate_temperature))
+            }
+
+            // Reads a pending no-hold measurement. The sensor NACKs the read while it's still
+            // converting; only that specific failure is reported as "not ready yet" (`Ok(None)`),
+            // any other I2C error (wrong address, bus fault, ...) is propagated as `T::Error`.
+            $($async_kw)* fn try_read_word(&mut self) -> Result<Option<u16>, T::Error> {
+                let mut buf = [0u8; 2];
+                match self.i2c.read(self.address, &mut buf)$($await_kw)* {
+                    Ok(()) => Ok(Some(BigEndian::read_u16(&buf))),
+                    Err(err) if matches!(err.kind(), ErrorKind::NoAcknowledge(_)) => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+
+            /// Turn the on-chip heater on or off, e.g. for condensation burn-off or low-humidity
+            /// self-diagnostics.
+            pub $($async_kw)* fn set_heater(&mut self, enabled: bool) -> Result<(), T::Error> {
+                let register = self.read_register(READ_USER_REGISTER_1)$($await_kw)*?;
+                let register = if enabled {
+                    register | HEATER_ENABLE_BIT
+                } else {
+                    register & !HEATER_ENABLE_BIT
+                };
+
+                self.write_register(WRITE_USER_REGISTER_1, register)$($await_kw)*
+            }
+
+            /// Select the heater current step, from `0x0` (~3 mA) to `0xF` (~94 mA). Only the low 4
+            /// bits of `level` are used. The heater itself must still be enabled with
+            /// [`set_heater`](Self::set_heater).
+            pub $($async_kw)* fn set_heater_power(&mut self, level: u8) -> Result<(), T::Error> {
+                let register = self.read_register(READ_HEATER_CONTROL_REGISTER)$($await_kw)*?;
+                let register = (register & !HEATER_CURRENT_MASK) | (level & HEATER_CURRENT_MASK);
+
+                self.write_register(WRITE_HEATER_CONTROL_REGISTER, register)$($await_kw)*
+            }
+
+            /// Read the approximate heater current, in mA, for the currently selected heater power
+            /// step.
+            pub $($async_kw)* fn heater_power(&mut self) -> Result<f32, T::Error> {
+                let register = self.read_register(READ_HEATER_CONTROL_REGISTER)$($await_kw)*?;
+                let level = register & HEATER_CURRENT_MASK;
+
+                Ok(heater_current_ma(level))
+            }
+
+            /// Read the factory-programmed 64-bit electronic serial number.
+            pub $($async_kw)* fn serial_number(&mut self) -> Result<u64, Error<T::Error>> {
+                let sna = self.read_sna()$($await_kw)*?;
+                let snb = self.read_snb()$($await_kw)*?;
+
+                let mut bytes = [0u8; 8];
+                bytes[..4].copy_from_slice(&sna);
+                bytes[4..].copy_from_slice(&snb);
+
+                Ok(BigEndian::read_u64(&bytes))
+            }
+
+            /// Identify which member of the Si7013/Si7020/Si7021 family is on the bus, so callers can
+            /// assert the expected sensor is present before trusting its readings.
+            pub $($async_kw)* fn device_id(&mut self) -> Result<DeviceId, Error<T::Error>> {
+                let snb = self.read_snb()$($await_kw)*?;
+
+                Ok(DeviceId::from_byte(snb[0]))
+            }
+
+            /// Read the firmware revision byte (`0xFF` for v1.0, `0x20` for v2.0).
+            pub $($async_kw)* fn firmware_revision(&mut self) -> Result<u8, T::Error> {
+                let mut buf = [0u8; 1];
+                self.i2c.write_read(self.address, &FIRMWARE_REVISION_ACCESS, &mut buf)$($await_kw)*?;
+
+                Ok(buf[0])
+            }
+
+            $($async_kw)* fn read_word(&mut self, command: u8) -> Result<u16, T::Error> {
+                let mut buf = [0u8; 2];
+                self.i2c.write_read(self.address, &[command], &mut buf)$($await_kw)*?;
+
+                Ok(BigEndian::read_u16(&buf))
+            }
+
+            $($async_kw)* fn read_word_checked(&mut self, command: u8) -> Result<u16, Error<T::Error>> {
+                let mut buf = [0u8; 3];
+                self.i2c.write_read(self.address, &[command], &mut buf)$($await_kw)*?;
+
+                if crc8(&buf[..2]) != buf[2] {
+                    return Err(Error::Crc);
+                }
+
+                Ok(BigEndian::read_u16(&buf[..2]))
+            }
+
+            $($async_kw)* fn read_register(&mut self, command: u8) -> Result<u8, T::Error> {
+                let mut buf = [0u8; 1];
+                self.i2c.write_read(self.address, &[command], &mut buf)$($await_kw)*?;
+
+                Ok(buf[0])
+            }
+
+            $($async_kw)* fn write_register(&mut self, command: u8, value: u8) -> Result<(), T::Error> {
+                self.i2c.write(self.address, &[command, value])$($await_kw)*
+            }
+
+            // Reads the first half of the electronic serial number (SNA_3..SNA_0): 4 data bytes,
+            // each followed by its own CRC-8 checksum.
+            $($async_kw)* fn read_sna(&mut self) -> Result<[u8; 4], Error<T::Error>> {
+                let mut buf = [0u8; 8];
+                self.i2c.write_read(self.address, &SERIAL_NUMBER_FIRST_ACCESS, &mut buf)$($await_kw)*?;
+
+                let mut bytes = [0u8; 4];
+                for i in 0..4 {
+                    bytes[i] = buf[i * 2];
+                    if crc8(&buf[i * 2..i * 2 + 1]) != buf[i * 2 + 1] {
+                        return Err(Error::Crc);
+                    }
+                }
+
+                Ok(bytes)
+            }
+
+            // Reads the second half of the electronic serial number (SNB_3..SNB_0), which the sensor
+            // lays out differently from SNA: 6 bytes as SNB_3, SNB_2, CRC, SNB_1, SNB_0, CRC, with
+            // each CRC-8 checksum covering the preceding pair of data bytes.
+            $($async_kw)* fn read_snb(&mut self) -> Result<[u8; 4], Error<T::Error>> {
+                let mut buf = [0u8; 6];
+                self.i2c.write_read(self.address, &SERIAL_NUMBER_SECOND_ACCESS, &mut buf)$($await_kw)*?;
+
+                let mut bytes = [0u8; 4];
+                for (i, pair) in buf.chunks(3).enumerate() {
+                    bytes[i * 2] = pair[0];
+                    bytes[i * 2 + 1] = pair[1];
+                    if crc8(&pair[..2]) != pair[2] {
+                        return Err(Error::Crc);
+                    }
+                }
+
+                Ok(bytes)
+            }
+
+            $($extra)*
+        }
+    };
+}
+#[cfg(feature = "async")]
+pub(crate) use si7021_driver_impl;
+
+/// Read temperature and relative humidity from a Si7021
+#[derive(Clone, Debug)]
+pub struct Si7021<T> {
+    i2c: T,
+    address: u8,
+}
+
+si7021_driver_impl! {
+    Si7021,
+    i2c_trait = I2c,
+    delay_trait = DelayNs,
+    hal_crate = "embedded-hal",
+    wait_verb = "blocks for",
+    rh_link = Hygrometer::relative_humidity,
+    temp_link = Thermometer::temperature_celsius,
+    async_kw = [],
+    await_kw = [],
+    extra_methods = {}
+}
+
+#[cfg(feature = "i2csensors")]
 impl<T> Hygrometer for Si7021<T>
-    where T: I2CDevice
+    where T: I2c, T::Error: core::error::Error
 {
     type Error = T::Error;
 
@@ -64,8 +484,9 @@ impl<T> Hygrometer for Si7021<T>
     }
 }
 
+#[cfg(feature = "i2csensors")]
 impl<T> Thermometer for Si7021<T>
-    where T: I2CDevice
+    where T: I2c, T::Error: core::error::Error
 {
     type Error = T::Error;
 
@@ -78,9 +499,62 @@ impl<T> Thermometer for Si7021<T>
 
 fn calculate_relative_humidity(raw_humidity: u16) -> f32 {
     let relative_humidity = 125.0 * raw_humidity as f32 / 65536.0 - 6.0;
-    relative_humidity.max(0.0).min(100.0) // clamp as per datasheet
+    relative_humidity.clamp(0.0, 100.0) // clamp as per datasheet
 }
 
 fn calculate_temperature(raw_temperature: u16) -> f32 {
     175.72 * raw_temperature as f32 / 65536.0 - 46.85
 }
+
+fn heater_current_ma(level: u8) -> f32 {
+    let step = (HEATER_CURRENT_MAX_MA - HEATER_CURRENT_MIN_MA) / HEATER_CURRENT_MASK as f32;
+    HEATER_CURRENT_MIN_MA + level as f32 * step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc8_known_vectors() {
+        // x^8 + x^5 + x^4 + 1 (0x31), initial value 0x00, MSB first.
+        assert_eq!(crc8(&[0x00]), 0x00);
+        assert_eq!(crc8(&[0xDC, 0x00]), 0x70);
+        assert_eq!(crc8(&[0xBE, 0xEF]), 0x13);
+    }
+
+    #[test]
+    fn resolution_bits_round_trip() {
+        for resolution in [
+            Resolution::Rh12Temp14,
+            Resolution::Rh8Temp12,
+            Resolution::Rh10Temp13,
+            Resolution::Rh11Temp11,
+        ] {
+            assert_eq!(Resolution::from_bits(resolution.to_bits()), resolution);
+        }
+    }
+
+    #[test]
+    fn resolution_from_bits_ignores_reserved_bits() {
+        // Bits 1-6 are reserved and must not affect the decoded resolution.
+        assert_eq!(Resolution::from_bits(0b0111_1110), Resolution::Rh12Temp14);
+        assert_eq!(Resolution::from_bits(0b1111_1111), Resolution::Rh11Temp11);
+    }
+
+    #[test]
+    fn heater_current_ma_endpoints() {
+        assert_eq!(heater_current_ma(0x0), HEATER_CURRENT_MIN_MA);
+        assert_eq!(heater_current_ma(0xF), HEATER_CURRENT_MAX_MA);
+    }
+
+    #[test]
+    fn device_id_from_byte() {
+        assert_eq!(DeviceId::from_byte(0x0D), DeviceId::Si7013);
+        assert_eq!(DeviceId::from_byte(0x14), DeviceId::Si7020);
+        assert_eq!(DeviceId::from_byte(0x15), DeviceId::Si7021);
+        assert_eq!(DeviceId::from_byte(0x00), DeviceId::EngineeringSample);
+        assert_eq!(DeviceId::from_byte(0xFF), DeviceId::EngineeringSample);
+        assert_eq!(DeviceId::from_byte(0x42), DeviceId::Unknown(0x42));
+    }
+}